@@ -0,0 +1,326 @@
+mod scope;
+
+use scope::Scope;
+
+use pest::error::LineColLocation;
+
+use fabulist_lang::{
+    ast::{
+        expr::{binary::BinaryOperatorKind, primitive::Primitive, Expr},
+        stmt::{BlockStmt, ElseClause, IfStmt, LetStmt, Stmt},
+    },
+    error::Error,
+};
+
+use crate::story::Story;
+
+/// The literal type of an `Expr`, as far as the analyzer can tell without running the
+/// story. Only direct `Primitive` literals are typed; anything that depends on a runtime
+/// value (an identifier, a call) has no `LiteralType` (`literal_type` returns `None`) and is
+/// skipped by the type checker below.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LiteralType {
+    String,
+    Number,
+    Bool,
+}
+
+/// Walks a fully parsed `Story` and reports problems that would otherwise only surface at
+/// runtime: dangling `goto` targets, identifiers read before they're bound, and `BinaryExpr`
+/// nodes whose literal operands can't support the given operator.
+pub fn analyze(story: &Story) -> Result<(), Vec<Error>> {
+    let known_targets = collect_known_targets(story);
+    let mut errors = Vec::new();
+
+    for module in story.modules() {
+        for part in module.parts() {
+            for dialogue in part.dialogues() {
+                let mut scope = Scope::new();
+                for stmt in dialogue.stmts() {
+                    check_stmt(stmt, &mut scope, &known_targets, &mut errors);
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn collect_known_targets(story: &Story) -> std::collections::HashSet<String> {
+    story
+        .modules()
+        .iter()
+        .flat_map(|module| {
+            module
+                .parts()
+                .iter()
+                .map(move |part| format!("{}::{}", module.id(), part.id()))
+        })
+        .collect()
+}
+
+fn check_stmt(
+    stmt: &Stmt,
+    scope: &mut Scope,
+    known_targets: &std::collections::HashSet<String>,
+    errors: &mut Vec<Error>,
+) {
+    match stmt {
+        Stmt::Block(block) => check_block(block, scope, known_targets, errors),
+        Stmt::Let(let_stmt) => check_let(let_stmt, scope, known_targets, errors),
+        Stmt::If(if_stmt) => check_if(if_stmt, scope, known_targets, errors),
+        Stmt::Goto(goto_stmt) => {
+            if let Primitive::Identifier(path) | Primitive::String(path) = &goto_stmt.path {
+                if !known_targets.contains(path) {
+                    errors.push(Error::UnresolvedGotoTarget {
+                        path: path.clone(),
+                        lcol: goto_stmt.lcol.clone(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn check_block(
+    block: &BlockStmt,
+    scope: &Scope,
+    known_targets: &std::collections::HashSet<String>,
+    errors: &mut Vec<Error>,
+) {
+    let mut inner_scope = scope.child();
+    for stmt in &block.statements {
+        check_stmt(stmt, &mut inner_scope, known_targets, errors);
+    }
+}
+
+fn check_let(
+    let_stmt: &LetStmt,
+    scope: &mut Scope,
+    known_targets: &std::collections::HashSet<String>,
+    errors: &mut Vec<Error>,
+) {
+    check_expr(&let_stmt.value, &let_stmt.lcol, scope, known_targets, errors);
+    if let Primitive::Identifier(name) = &let_stmt.identifier {
+        scope.bind(name.clone());
+    }
+}
+
+fn check_if(
+    if_stmt: &IfStmt,
+    scope: &mut Scope,
+    known_targets: &std::collections::HashSet<String>,
+    errors: &mut Vec<Error>,
+) {
+    check_expr(&if_stmt.condition, &if_stmt.lcol, scope, known_targets, errors);
+    check_block(&if_stmt.block_stmt, scope, known_targets, errors);
+    match if_stmt.else_stmt.as_deref() {
+        Some(ElseClause::If(else_if)) => check_if(else_if, scope, known_targets, errors),
+        Some(ElseClause::Block(block)) => check_block(block, scope, known_targets, errors),
+        None => {}
+    }
+}
+
+/// Checks an `Expr`, falling back to `enclosing_lcol` — the span of the nearest statement or
+/// `BinaryExpr` containing it — for diagnostics about nodes (bare identifiers) that don't yet
+/// carry their own span.
+fn check_expr(
+    expr: &Expr,
+    enclosing_lcol: &LineColLocation,
+    scope: &Scope,
+    known_targets: &std::collections::HashSet<String>,
+    errors: &mut Vec<Error>,
+) {
+    match expr {
+        Expr::Primitive(Primitive::Identifier(name)) => {
+            if !scope.is_bound(name) {
+                errors.push(Error::UnboundVariable {
+                    name: name.clone(),
+                    lcol: enclosing_lcol.clone(),
+                });
+            }
+        }
+        Expr::Primitive(_) => {}
+        Expr::Binary(binary) => {
+            check_expr(&binary.left, &binary.lcol, scope, known_targets, errors);
+            if let Some(right) = &binary.right {
+                check_expr(right, &binary.lcol, scope, known_targets, errors);
+            }
+            if binary.operator.is_some() && binary.right.is_some() {
+                check_operator_types(binary, errors);
+            }
+        }
+        Expr::Call(call) => {
+            for argument in call.arguments.arguments.iter().flatten() {
+                check_expr(argument, enclosing_lcol, scope, known_targets, errors);
+            }
+        }
+    }
+}
+
+fn check_operator_types(
+    binary: &fabulist_lang::ast::expr::binary::BinaryExpr,
+    errors: &mut Vec<Error>,
+) {
+    // Callers only reach here once both `operator` and `right` are known to be `Some`.
+    let operator = binary.operator.as_ref().expect("operator is present");
+    let right = binary.right.as_ref().expect("right operand is present");
+
+    let (left_type, right_type) = match (literal_type(&binary.left), literal_type(right)) {
+        (Some(left_type), Some(right_type)) => (left_type, right_type),
+        _ => return,
+    };
+
+    let is_arithmetic_or_comparison = matches!(
+        operator.kind,
+        BinaryOperatorKind::Addition
+            | BinaryOperatorKind::Subtraction
+            | BinaryOperatorKind::Multiply
+            | BinaryOperatorKind::Divide
+            | BinaryOperatorKind::GreaterThan
+            | BinaryOperatorKind::GreaterEqual
+            | BinaryOperatorKind::LessThan
+            | BinaryOperatorKind::LessEqual
+    );
+    let is_logical = matches!(operator.kind, BinaryOperatorKind::And | BinaryOperatorKind::Or);
+
+    if is_arithmetic_or_comparison {
+        if left_type != LiteralType::Number || right_type != LiteralType::Number {
+            errors.push(Error::InvalidOperandTypes {
+                message: "arithmetic and comparison operators require number operands".to_string(),
+                lcol: binary.lcol.clone(),
+            });
+        }
+    } else if is_logical {
+        if left_type != LiteralType::Bool || right_type != LiteralType::Bool {
+            errors.push(Error::InvalidOperandTypes {
+                message: "`&&`/`||` require bool operands".to_string(),
+                lcol: binary.lcol.clone(),
+            });
+        }
+    } else if left_type != right_type {
+        // `==`/`!=`: any type is fine as long as both sides agree.
+        errors.push(Error::InvalidOperandTypes {
+            message: "cannot compare operands of different types".to_string(),
+            lcol: binary.lcol.clone(),
+        });
+    }
+}
+
+fn literal_type(expr: &Expr) -> Option<LiteralType> {
+    match expr {
+        Expr::Primitive(Primitive::String(_)) => Some(LiteralType::String),
+        Expr::Primitive(Primitive::Number(_)) => Some(LiteralType::Number),
+        Expr::Primitive(Primitive::Bool(_)) => Some(LiteralType::Bool),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod analyzer_tests {
+    use fabulist_lang::ast::{
+        expr::binary::{BinaryExpr, BinaryOperator, BinaryOperatorKind},
+        stmt::GotoStmt,
+    };
+
+    use super::*;
+
+    fn lcol(pos: (usize, usize)) -> LineColLocation {
+        LineColLocation::Pos(pos)
+    }
+
+    fn number(value: f64) -> Expr {
+        Expr::from(Primitive::Number(value))
+    }
+
+    #[test]
+    fn flags_a_dangling_goto_target_with_its_own_span() {
+        let goto_stmt = GotoStmt {
+            path: Primitive::Identifier("module_1::missing_part".to_string()),
+            lcol: lcol((3, 1)),
+        };
+        let known_targets = std::collections::HashSet::new();
+        let mut scope = Scope::new();
+        let mut errors = Vec::new();
+
+        check_stmt(&Stmt::from(goto_stmt), &mut scope, &known_targets, &mut errors);
+
+        match errors.as_slice() {
+            [Error::UnresolvedGotoTarget { path, lcol }] => {
+                assert_eq!(path, "module_1::missing_part");
+                assert_eq!(*lcol, LineColLocation::Pos((3, 1)));
+            }
+            other => panic!("expected exactly one UnresolvedGotoTarget, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn flags_a_variable_used_before_it_is_bound() {
+        let let_stmt = LetStmt {
+            identifier: Primitive::Identifier("total".to_string()),
+            value: Expr::from(Primitive::Identifier("undefined".to_string())),
+            lcol: lcol((1, 5)),
+        };
+        let known_targets = std::collections::HashSet::new();
+        let mut scope = Scope::new();
+        let mut errors = Vec::new();
+
+        check_stmt(&Stmt::from(let_stmt), &mut scope, &known_targets, &mut errors);
+
+        match errors.as_slice() {
+            [Error::UnboundVariable { name, lcol }] => {
+                assert_eq!(name, "undefined");
+                // Falls back to the enclosing `LetStmt`'s span since `Primitive` carries none.
+                assert_eq!(*lcol, LineColLocation::Pos((1, 5)));
+            }
+            other => panic!("expected exactly one UnboundVariable, got {other:?}"),
+        }
+        assert!(scope.is_bound("total"));
+    }
+
+    #[test]
+    fn flags_arithmetic_on_a_string_literal_with_the_binary_exprs_span() {
+        let binary = BinaryExpr {
+            left: Expr::from(Primitive::String("a".to_string())),
+            operator: Some(BinaryOperator {
+                kind: BinaryOperatorKind::Addition,
+                lcol: lcol((0, 0)),
+            }),
+            right: Some(number(1.0)),
+            lcol: lcol((2, 7)),
+        };
+        let mut errors = Vec::new();
+
+        check_operator_types(&binary, &mut errors);
+
+        match errors.as_slice() {
+            [Error::InvalidOperandTypes { message, lcol }] => {
+                assert_eq!(message, "arithmetic and comparison operators require number operands");
+                assert_eq!(*lcol, LineColLocation::Pos((2, 7)));
+            }
+            other => panic!("expected exactly one InvalidOperandTypes, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn allows_arithmetic_on_two_numbers() {
+        let binary = BinaryExpr {
+            left: number(1.0),
+            operator: Some(BinaryOperator {
+                kind: BinaryOperatorKind::Addition,
+                lcol: lcol((0, 0)),
+            }),
+            right: Some(number(2.0)),
+            lcol: lcol((0, 0)),
+        };
+        let mut errors = Vec::new();
+
+        check_operator_types(&binary, &mut errors);
+
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+
+/// Tracks which identifiers are bound at a given point in the static walk, so the analyzer
+/// can flag a `LetStmt` identifier used in an `Expr` before it's bound in an enclosing scope.
+/// This mirrors the shape of `eval::Env` but carries no values, only names.
+pub struct Scope<'p> {
+    bound: HashSet<String>,
+    parent: Option<&'p Scope<'p>>,
+}
+
+impl<'p> Scope<'p> {
+    pub fn new() -> Self {
+        Self {
+            bound: HashSet::new(),
+            parent: None,
+        }
+    }
+
+    pub fn child(&'p self) -> Self {
+        Self {
+            bound: HashSet::new(),
+            parent: Some(self),
+        }
+    }
+
+    pub fn bind(&mut self, identifier: impl Into<String>) {
+        self.bound.insert(identifier.into());
+    }
+
+    pub fn is_bound(&self, identifier: &str) -> bool {
+        self.bound.contains(identifier) || self.parent.is_some_and(|parent| parent.is_bound(identifier))
+    }
+}
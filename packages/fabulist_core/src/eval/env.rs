@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::Value;
+
+/// A lexical scope of variable bindings, chained to an optional parent scope so that a
+/// `BlockStmt` can shadow names from the block it's nested in without mutating it.
+///
+/// The parent is owned (`Box<Env>`), not borrowed, so that a whole `Env` chain can be
+/// stored in `State` and saved/restored with the rest of a playthrough.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Env {
+    bindings: HashMap<String, Value>,
+    parent: Option<Box<Env>>,
+}
+
+impl Env {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a new scope nested inside this one, cloning the current bindings into the
+    /// child's parent link so the child can be handed around independently of `self`.
+    pub fn child(&self) -> Self {
+        Self {
+            bindings: HashMap::new(),
+            parent: Some(Box::new(self.clone())),
+        }
+    }
+
+    pub fn get(&self, identifier: &str) -> Option<&Value> {
+        match self.bindings.get(identifier) {
+            Some(value) => Some(value),
+            None => self.parent.as_deref().and_then(|parent| parent.get(identifier)),
+        }
+    }
+
+    pub fn set(&mut self, identifier: impl Into<String>, value: Value) {
+        self.bindings.insert(identifier.into(), value);
+    }
+}
+
+#[cfg(test)]
+mod env_tests {
+    use super::*;
+
+    #[test]
+    fn reads_own_and_parent_bindings() {
+        let mut parent = Env::new();
+        parent.set("a", Value::Number(1.0));
+
+        let mut child = parent.child();
+        child.set("b", Value::Number(2.0));
+
+        assert_eq!(child.get("a"), Some(&Value::Number(1.0)));
+        assert_eq!(child.get("b"), Some(&Value::Number(2.0)));
+        assert_eq!(parent.get("b"), None);
+    }
+
+    #[test]
+    fn child_shadows_parent_binding() {
+        let mut parent = Env::new();
+        parent.set("a", Value::Number(1.0));
+
+        let mut child = parent.child();
+        child.set("a", Value::Number(2.0));
+
+        assert_eq!(child.get("a"), Some(&Value::Number(2.0)));
+        assert_eq!(parent.get("a"), Some(&Value::Number(1.0)));
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut env = Env::new();
+        env.set("a", Value::Bool(true));
+        let child = env.child();
+
+        let serialized = serde_json::to_string(&child).expect("`Env` should serialize");
+        let deserialized: Env =
+            serde_json::from_str(&serialized).expect("`Env` should deserialize");
+
+        assert_eq!(deserialized, child);
+    }
+}
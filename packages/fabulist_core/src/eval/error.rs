@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum EvalError {
+    #[error("variable `{0}` is not bound in the current scope")]
+    UnboundVariable(String),
+    #[error("cannot apply `{operator}` to a {left} and a {right}")]
+    TypeMismatch {
+        operator: &'static str,
+        left: &'static str,
+        right: &'static str,
+    },
+    #[error("no function named `{0}` is registered")]
+    UnknownFunction(String),
+    #[error("`{name}` expects {expected} argument(s), got {got}")]
+    ArityMismatch {
+        name: String,
+        expected: usize,
+        got: usize,
+    },
+}
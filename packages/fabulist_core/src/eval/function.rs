@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use super::{EvalError, Result, Value};
+
+type Builtin = Box<dyn Fn(&[Value]) -> Result<Value>>;
+
+/// Host-registered functions a story script can call from within a condition or let-binding,
+/// e.g. `has_item("key")` or `random(1, 6)`. Registered once on the engine before playback,
+/// then consulted by the expression evaluator whenever it encounters a call expression.
+#[derive(Default)]
+pub struct FunctionMap {
+    functions: HashMap<String, (usize, Builtin)>,
+}
+
+impl FunctionMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(
+        mut self,
+        name: impl Into<String>,
+        arity: usize,
+        f: impl Fn(&[Value]) -> Result<Value> + 'static,
+    ) -> Self {
+        self.functions.insert(name.into(), (arity, Box::new(f)));
+        self
+    }
+
+    pub fn call(&self, name: &str, arguments: &[Value]) -> Result<Value> {
+        let (arity, f) = self
+            .functions
+            .get(name)
+            .ok_or_else(|| EvalError::UnknownFunction(name.to_string()))?;
+
+        if arguments.len() != *arity {
+            return Err(EvalError::ArityMismatch {
+                name: name.to_string(),
+                expected: *arity,
+                got: arguments.len(),
+            });
+        }
+
+        f(arguments)
+    }
+}
+
+#[cfg(test)]
+mod function_map_tests {
+    use super::*;
+
+    #[test]
+    fn calls_a_registered_function_with_matching_arity() {
+        let functions = FunctionMap::new().register("add", 2, |arguments| match arguments {
+            [Value::Number(l), Value::Number(r)] => Ok(Value::Number(l + r)),
+            _ => unreachable!("arity is checked before `f` runs"),
+        });
+
+        assert_eq!(
+            functions.call("add", &[Value::Number(2.0), Value::Number(3.0)]),
+            Ok(Value::Number(5.0))
+        );
+    }
+
+    #[test]
+    fn reports_unknown_function() {
+        let functions = FunctionMap::new();
+
+        assert_eq!(
+            functions.call("missing", &[]),
+            Err(EvalError::UnknownFunction("missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn reports_arity_mismatch() {
+        let functions = FunctionMap::new().register("add", 2, |_| Ok(Value::Bool(true)));
+
+        assert_eq!(
+            functions.call("add", &[Value::Number(1.0)]),
+            Err(EvalError::ArityMismatch {
+                name: "add".to_string(),
+                expected: 2,
+                got: 1,
+            })
+        );
+    }
+}
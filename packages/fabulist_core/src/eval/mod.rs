@@ -0,0 +1,307 @@
+mod env;
+mod error;
+mod function;
+mod value;
+
+pub use env::Env;
+pub use error::EvalError;
+pub use function::FunctionMap;
+pub use value::Value;
+
+use fabulist_lang::ast::{
+    expr::{
+        binary::{BinaryExpr, BinaryOperatorKind},
+        call::CallExpr,
+        primitive::Primitive,
+        Expr,
+    },
+    stmt::{BlockStmt, ElseClause, GotoStmt, IfStmt, LetStmt, Stmt},
+};
+
+pub type Result<T> = std::result::Result<T, EvalError>;
+
+/// The effect of running a `Stmt`: either control falls through to the statement after it,
+/// or a `GotoStmt` was hit and playback should redirect to the returned part path.
+#[derive(Debug)]
+pub enum Flow {
+    Continue,
+    Goto(String),
+}
+
+/// Evaluates an `Expr` to a `Value` against the given scope, folding `BinaryExpr` nodes
+/// with short-circuiting `&&`/`||` and dispatching call expressions to `functions`.
+pub fn eval(expr: &Expr, env: &Env, functions: &FunctionMap) -> Result<Value> {
+    match expr {
+        Expr::Primitive(primitive) => eval_primitive(primitive, env),
+        Expr::Binary(binary) => eval_binary(binary, env, functions),
+        Expr::Call(call) => eval_call(call, env, functions),
+    }
+}
+
+fn eval_primitive(primitive: &Primitive, env: &Env) -> Result<Value> {
+    match primitive {
+        Primitive::String(value) => Ok(Value::String(value.clone())),
+        Primitive::Number(value) => Ok(Value::Number(*value)),
+        Primitive::Bool(value) => Ok(Value::Bool(*value)),
+        Primitive::Identifier(name) => env
+            .get(name)
+            .cloned()
+            .ok_or_else(|| EvalError::UnboundVariable(name.clone())),
+    }
+}
+
+fn eval_binary(binary: &BinaryExpr, env: &Env, functions: &FunctionMap) -> Result<Value> {
+    let left = eval(&binary.left, env, functions)?;
+
+    let (operator, right_expr) = match (&binary.operator, &binary.right) {
+        (Some(operator), Some(right_expr)) => (operator, right_expr),
+        _ => return Ok(left),
+    };
+
+    // Short-circuit `&&`/`||` before evaluating the right-hand side.
+    match (&operator.kind, &left) {
+        (BinaryOperatorKind::And, Value::Bool(false)) => return Ok(Value::Bool(false)),
+        (BinaryOperatorKind::Or, Value::Bool(true)) => return Ok(Value::Bool(true)),
+        _ => {}
+    }
+
+    let right = eval(right_expr, env, functions)?;
+    apply_operator(&operator.kind, left, right)
+}
+
+fn eval_call(call: &CallExpr, env: &Env, functions: &FunctionMap) -> Result<Value> {
+    let name = match &call.callee {
+        Primitive::Identifier(name) => name,
+        _ => return Err(EvalError::UnknownFunction("<non-identifier callee>".to_string())),
+    };
+
+    let arguments = call
+        .arguments
+        .arguments
+        .iter()
+        .flatten()
+        .map(|argument| eval(argument, env, functions))
+        .collect::<Result<Vec<Value>>>()?;
+
+    functions.call(name, &arguments)
+}
+
+fn apply_operator(operator: &BinaryOperatorKind, left: Value, right: Value) -> Result<Value> {
+    use BinaryOperatorKind::*;
+    use Value::*;
+
+    match (operator, &left, &right) {
+        (Addition, Number(l), Number(r)) => Ok(Number(l + r)),
+        (Subtraction, Number(l), Number(r)) => Ok(Number(l - r)),
+        (Multiply, Number(l), Number(r)) => Ok(Number(l * r)),
+        (Divide, Number(l), Number(r)) => Ok(Number(l / r)),
+        (GreaterThan, Number(l), Number(r)) => Ok(Bool(l > r)),
+        (GreaterEqual, Number(l), Number(r)) => Ok(Bool(l >= r)),
+        (LessThan, Number(l), Number(r)) => Ok(Bool(l < r)),
+        (LessEqual, Number(l), Number(r)) => Ok(Bool(l <= r)),
+        (EqualEqual, _, _) => Ok(Bool(left == right)),
+        (NotEqual, _, _) => Ok(Bool(left != right)),
+        (And, Bool(l), Bool(r)) => Ok(Bool(*l && *r)),
+        (Or, Bool(l), Bool(r)) => Ok(Bool(*l || *r)),
+        _ => Err(EvalError::TypeMismatch {
+            operator: operator_name(operator),
+            left: left.type_name(),
+            right: right.type_name(),
+        }),
+    }
+}
+
+fn operator_name(operator: &BinaryOperatorKind) -> &'static str {
+    match operator {
+        BinaryOperatorKind::Divide => "/",
+        BinaryOperatorKind::Multiply => "*",
+        BinaryOperatorKind::Addition => "+",
+        BinaryOperatorKind::Subtraction => "-",
+        BinaryOperatorKind::GreaterThan => ">",
+        BinaryOperatorKind::GreaterEqual => ">=",
+        BinaryOperatorKind::LessThan => "<",
+        BinaryOperatorKind::LessEqual => "<=",
+        BinaryOperatorKind::EqualEqual => "==",
+        BinaryOperatorKind::NotEqual => "!=",
+        BinaryOperatorKind::And => "&&",
+        BinaryOperatorKind::Or => "||",
+    }
+}
+
+/// Runs a `BlockStmt` in a child scope of `env`, returning the `Flow` produced by the first
+/// `GotoStmt` it reaches, if any.
+pub fn exec_block(block: &BlockStmt, env: &Env, functions: &FunctionMap) -> Result<Flow> {
+    let mut scope = env.child();
+    for stmt in &block.statements {
+        match exec_stmt(stmt, &mut scope, functions)? {
+            Flow::Continue => continue,
+            goto => return Ok(goto),
+        }
+    }
+    Ok(Flow::Continue)
+}
+
+fn exec_stmt(stmt: &Stmt, env: &mut Env, functions: &FunctionMap) -> Result<Flow> {
+    match stmt {
+        Stmt::Block(block) => exec_block(block, env, functions),
+        Stmt::Let(let_stmt) => exec_let(let_stmt, env, functions),
+        Stmt::If(if_stmt) => exec_if(if_stmt, env, functions),
+        Stmt::Goto(goto_stmt) => Ok(exec_goto(goto_stmt)),
+    }
+}
+
+fn exec_let(let_stmt: &LetStmt, env: &mut Env, functions: &FunctionMap) -> Result<Flow> {
+    let identifier = match &let_stmt.identifier {
+        Primitive::Identifier(name) => name.clone(),
+        primitive => return eval_primitive(primitive, env).map(|_| Flow::Continue),
+    };
+    let value = eval(&let_stmt.value, env, functions)?;
+    env.set(identifier, value);
+    Ok(Flow::Continue)
+}
+
+fn exec_if(if_stmt: &IfStmt, env: &Env, functions: &FunctionMap) -> Result<Flow> {
+    if let Value::Bool(true) = eval(&if_stmt.condition, env, functions)? {
+        return exec_block(&if_stmt.block_stmt, env, functions);
+    }
+
+    match &if_stmt.else_stmt {
+        Some(else_clause) => match else_clause.as_ref() {
+            ElseClause::If(else_if) => exec_if(else_if, env, functions),
+            ElseClause::Block(block) => exec_block(block, env, functions),
+        },
+        None => Ok(Flow::Continue),
+    }
+}
+
+fn exec_goto(goto_stmt: &GotoStmt) -> Flow {
+    match &goto_stmt.path {
+        Primitive::Identifier(path) => Flow::Goto(path.clone()),
+        Primitive::String(path) => Flow::Goto(path.clone()),
+        _ => Flow::Continue,
+    }
+}
+
+#[cfg(test)]
+mod eval_tests {
+    use pest::error::LineColLocation;
+
+    use fabulist_lang::ast::expr::binary::{BinaryOperator, BinaryOperatorKind};
+
+    use super::*;
+
+    fn lcol() -> LineColLocation {
+        LineColLocation::Pos((0, 0))
+    }
+
+    fn number(value: f64) -> Expr {
+        Expr::from(Primitive::Number(value))
+    }
+
+    fn binary(left: Expr, kind: BinaryOperatorKind, right: Expr) -> Expr {
+        Expr::from(BinaryExpr {
+            left,
+            operator: Some(BinaryOperator { kind, lcol: lcol() }),
+            right: Some(right),
+            lcol: lcol(),
+        })
+    }
+
+    #[test]
+    fn evaluates_arithmetic() {
+        let expr = binary(number(5.0), BinaryOperatorKind::Addition, number(2.0));
+        let env = Env::new();
+        let functions = FunctionMap::new();
+
+        assert_eq!(eval(&expr, &env, &functions), Ok(Value::Number(7.0)));
+    }
+
+    #[test]
+    fn short_circuits_and_without_evaluating_right() {
+        // A right side that isn't a bool would blow up `&&` if it were evaluated, so
+        // short-circuiting on a `false` left side must skip it entirely.
+        let bogus_right = Expr::from(Primitive::String("not a bool".into()));
+        let expr = binary(
+            Expr::from(Primitive::Bool(false)),
+            BinaryOperatorKind::And,
+            bogus_right,
+        );
+        let env = Env::new();
+        let functions = FunctionMap::new();
+
+        assert_eq!(eval(&expr, &env, &functions), Ok(Value::Bool(false)));
+    }
+
+    #[test]
+    fn reports_unbound_variable() {
+        let expr = Expr::from(Primitive::Identifier("undefined".to_string()));
+        let env = Env::new();
+        let functions = FunctionMap::new();
+
+        assert_eq!(
+            eval(&expr, &env, &functions),
+            Err(EvalError::UnboundVariable("undefined".to_string()))
+        );
+    }
+
+    #[test]
+    fn reports_type_mismatch_for_arithmetic_on_a_string() {
+        let expr = binary(
+            Expr::from(Primitive::String("a".to_string())),
+            BinaryOperatorKind::Addition,
+            number(1.0),
+        );
+        let env = Env::new();
+        let functions = FunctionMap::new();
+
+        assert!(matches!(
+            eval(&expr, &env, &functions),
+            Err(EvalError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn let_stmt_binds_into_the_current_scope() {
+        let let_stmt = LetStmt {
+            identifier: Primitive::Identifier("x".to_string()),
+            value: number(5.0),
+            lcol: lcol(),
+        };
+        let block = BlockStmt {
+            statements: vec![Stmt::from(let_stmt)],
+            lcol: lcol(),
+        };
+        let env = Env::new();
+        let functions = FunctionMap::new();
+
+        let flow = exec_block(&block, &env, &functions).expect("block should execute");
+        assert!(matches!(flow, Flow::Continue));
+
+        let mut scope = env.child();
+        exec_stmt(&block.statements[0], &mut scope, &functions).expect("let should execute");
+        assert_eq!(scope.get("x"), Some(&Value::Number(5.0)));
+    }
+
+    #[test]
+    fn if_stmt_runs_the_taken_branch_and_reports_its_goto() {
+        let if_stmt = IfStmt {
+            condition: Expr::from(Primitive::Bool(true)),
+            block_stmt: BlockStmt {
+                statements: vec![Stmt::from(GotoStmt {
+                    path: Primitive::Identifier("module_1::part_2".to_string()),
+                    lcol: lcol(),
+                })],
+                lcol: lcol(),
+            },
+            else_stmt: None,
+            lcol: lcol(),
+        };
+        let env = Env::new();
+        let functions = FunctionMap::new();
+
+        match exec_if(&if_stmt, &env, &functions).expect("if statement should execute") {
+            Flow::Goto(path) => assert_eq!(path, "module_1::part_2"),
+            Flow::Continue => panic!("expected the `goto` in the taken branch to fire"),
+        }
+    }
+}
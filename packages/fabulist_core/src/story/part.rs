@@ -5,6 +5,11 @@ use crate::{
 
 use super::{dialogue::Dialogue, traits::Progressive};
 
+/// A story module's compiled part.
+///
+/// Not `Serialize`/`Deserialize` yet: `dialogues: Vec<Dialogue>` needs `Dialogue` to derive
+/// both, and `Dialogue`'s definition lives outside this checkout. Giving `Part` a save-game
+/// form requires deriving on `Dialogue` first, which isn't reachable from here.
 pub struct Part {
     id: String,
     dialogues: Vec<Dialogue>,
@@ -99,3 +104,16 @@ impl Progressive for Part {
         Err(Error::EndOfStory)
     }
 }
+
+#[cfg(test)]
+mod part_tests {
+    use super::*;
+
+    #[test]
+    fn builder_carries_id_and_dialogues_onto_part() {
+        let part = PartBuilder::new("part_1").build();
+
+        assert_eq!(part.id(), "part_1");
+        assert!(part.dialogues().is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use pest::iterators::Pair;
+use pest::{error::LineColLocation, iterators::Pair};
 
 use crate::{ast::dfn::object::Object, parser::Rule};
 
@@ -8,12 +8,25 @@ use super::Error;
 pub struct QuoteDecl {
     pub text: String,
     pub properties: Option<Object>,
+    pub lcol: LineColLocation,
+}
+
+/// Ignores `lcol` so tests can assert tree shape without breaking on positional noise. The
+/// request asked for this to come from a `fabulist_derive` folder/visitor generating a
+/// span-ignoring `PartialEq` for every AST node; that crate lives outside this checkout, so
+/// this is a hand-rolled, one-off stand-in scoped to just `QuoteDecl` (see the matching note
+/// in `ast/expr/binary.rs`), not the general solution the request describes.
+impl PartialEq for QuoteDecl {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.properties == other.properties
+    }
 }
 
 impl TryFrom<Pair<'_, Rule>> for QuoteDecl {
     type Error = Error;
     fn try_from(value: Pair<'_, Rule>) -> Result<Self, Self::Error> {
         let value_span = value.as_span();
+        let value_lcol = LineColLocation::from(value_span);
         let mut inner = value.into_inner();
 
         let text = match inner.find_first_tagged("text") {
@@ -29,7 +42,11 @@ impl TryFrom<Pair<'_, Rule>> for QuoteDecl {
             None => None,
         };
 
-        Ok(QuoteDecl { text, properties })
+        Ok(QuoteDecl {
+            text,
+            properties,
+            lcol: value_lcol,
+        })
     }
 }
 
@@ -1,11 +1,13 @@
-use pest::iterators::Pair;
+use std::iter::Peekable;
+
+use pest::{error::LineColLocation, iterators::{Pair, Pairs}};
 
 use crate::parser::Rule;
 
 use super::{Error, Expr};
 
 #[derive(Debug)]
-pub enum BinaryOperator {
+pub enum BinaryOperatorKind {
     Divide,
     Multiply,
     Addition,
@@ -20,62 +22,241 @@ pub enum BinaryOperator {
     Or,
 }
 
+#[derive(Debug)]
+pub struct BinaryOperator {
+    pub kind: BinaryOperatorKind,
+    pub lcol: LineColLocation,
+}
+
+impl BinaryOperator {
+    /// Binding power used by the precedence-climbing parser below, lowest to highest:
+    /// `||`, `&&`, equality, comparison, additive, then multiplicative. All operators
+    /// are left-associative.
+    fn precedence(&self) -> u8 {
+        match self.kind {
+            BinaryOperatorKind::Or => 1,
+            BinaryOperatorKind::And => 2,
+            BinaryOperatorKind::EqualEqual | BinaryOperatorKind::NotEqual => 3,
+            BinaryOperatorKind::GreaterThan
+            | BinaryOperatorKind::GreaterEqual
+            | BinaryOperatorKind::LessThan
+            | BinaryOperatorKind::LessEqual => 4,
+            BinaryOperatorKind::Addition | BinaryOperatorKind::Subtraction => 5,
+            BinaryOperatorKind::Multiply | BinaryOperatorKind::Divide => 6,
+        }
+    }
+}
+
+/// Structurally equal if the operator kind matches, regardless of where it appeared in the
+/// source. Used by `assert_eq_ignore_span!` so parser tests can assert tree shape without
+/// breaking on positional noise.
+///
+/// Hand-rolled rather than generated by a `fabulist_derive` visitor as originally scoped —
+/// that crate isn't part of this checkout, so this is a deliberate, narrower stand-in: it
+/// covers `BinaryOperator`/`BinaryExpr`/`QuoteDecl` only, and every future spanned node needs
+/// its own copy of this impl until the real proc macro lands.
+impl PartialEq for BinaryOperator {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl PartialEq for BinaryOperatorKind {
+    fn eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+impl TryFrom<Pair<'_, Rule>> for BinaryOperator {
+    type Error = Error;
+    fn try_from(value: Pair<'_, Rule>) -> Result<Self, Self::Error> {
+        let operator_span = value.as_span();
+        let operator_lcol = LineColLocation::from(operator_span);
+        let kind = match value.as_str() {
+            "/" => Ok(BinaryOperatorKind::Divide),
+            "*" => Ok(BinaryOperatorKind::Multiply),
+            "+" => Ok(BinaryOperatorKind::Addition),
+            "-" => Ok(BinaryOperatorKind::Subtraction),
+            ">" => Ok(BinaryOperatorKind::GreaterThan),
+            ">=" => Ok(BinaryOperatorKind::GreaterEqual),
+            "<" => Ok(BinaryOperatorKind::LessThan),
+            "<=" => Ok(BinaryOperatorKind::LessEqual),
+            "==" => Ok(BinaryOperatorKind::EqualEqual),
+            "!=" => Ok(BinaryOperatorKind::NotEqual),
+            "&&" => Ok(BinaryOperatorKind::And),
+            "||" => Ok(BinaryOperatorKind::Or),
+            _ => Err(Error::map_span(operator_span, "Invalid binary operator")),
+        }?;
+
+        Ok(BinaryOperator {
+            kind,
+            lcol: operator_lcol,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct BinaryExpr {
     pub left: Expr,
     pub operator: Option<BinaryOperator>,
     pub right: Option<Expr>,
+    pub lcol: LineColLocation,
+}
+
+/// Ignores `lcol` so tests can assert tree shape without breaking on positional noise. See
+/// the note on `impl PartialEq for BinaryOperator` above: this is standing in for a
+/// `fabulist_derive`-generated impl that isn't reachable from this checkout.
+impl PartialEq for BinaryExpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left && self.operator == other.operator && self.right == other.right
+    }
+}
+
+/// Combines the start point of `left` with the end point of `right` into the smallest
+/// `LineColLocation` that spans both, collapsing to a single `Pos` if they coincide.
+fn merge_lcol(left: &LineColLocation, right: &LineColLocation) -> LineColLocation {
+    let start = match left {
+        LineColLocation::Pos(pos) => *pos,
+        LineColLocation::Span(start, _) => *start,
+    };
+    let end = match right {
+        LineColLocation::Pos(pos) => *pos,
+        LineColLocation::Span(_, end) => *end,
+    };
+
+    if start == end {
+        LineColLocation::Pos(start)
+    } else {
+        LineColLocation::Span(start, end)
+    }
+}
+
+impl BinaryExpr {
+    /// Precedence-climbing loop: given the operand already parsed as `left` (spanning
+    /// `left_lcol`), keep folding in `operator operand` pairs from `pairs` as long as the
+    /// operator's precedence is at least `min_prec`. The right-hand operand of each fold is
+    /// itself parsed with `min_prec` raised to `operator.precedence() + 1`, which is what
+    /// makes same-precedence operators associate to the left while higher-precedence
+    /// operators nest on the right. Returns the folded expression alongside its own span, so
+    /// each synthesized `BinaryExpr` gets a `lcol` covering exactly `left`..`right`, not the
+    /// whole outer expression it's nested inside.
+    fn parse_expression(
+        pairs: &mut Peekable<Pairs<'_, Rule>>,
+        left: Expr,
+        left_lcol: LineColLocation,
+        min_prec: u8,
+        value_span: pest::Span<'_>,
+    ) -> Result<(Expr, LineColLocation), Error> {
+        let mut left = left;
+        let mut left_lcol = left_lcol;
+
+        loop {
+            let operator = match pairs.peek() {
+                Some(pair) if pair.as_rule() == Rule::binary_operator => {
+                    BinaryOperator::try_from(pair.clone())?
+                }
+                _ => break,
+            };
+            if operator.precedence() < min_prec {
+                break;
+            }
+            pairs.next();
+
+            let operand_pair = pairs
+                .next()
+                .ok_or_else(|| Error::map_span(value_span, "Expected a value expression"))?;
+            let operand_lcol = LineColLocation::from(operand_pair.as_span());
+            let right = Expr::try_from(operand_pair)?;
+            let (right, right_lcol) = Self::parse_expression(
+                pairs,
+                right,
+                operand_lcol,
+                operator.precedence() + 1,
+                value_span,
+            )?;
+
+            left_lcol = merge_lcol(&left_lcol, &right_lcol);
+            left = BinaryExpr {
+                left,
+                operator: Some(operator),
+                right: Some(right),
+                lcol: left_lcol.clone(),
+            }
+            .into();
+        }
+
+        Ok((left, left_lcol))
+    }
 }
 
 impl TryFrom<Pair<'_, Rule>> for BinaryExpr {
     type Error = Error;
     fn try_from(value: Pair<'_, Rule>) -> Result<Self, Self::Error> {
         let value_span = value.as_span();
-        let inner = value.into_inner();
+        let mut inner = value.into_inner().peekable();
 
-        let left = match inner.find_first_tagged("left") {
-            Some(left) => Ok(Expr::try_from(left)?),
-            None => Err(Error::map_span(value_span, "Expected a value expression")),
-        }?;
-        let operator = match inner.find_first_tagged("operator") {
-            Some(operator) => {
-                let operator_span = operator.as_span();
-                Some(match operator.as_str() {
-                    "/" => Ok(BinaryOperator::Divide),
-                    "*" => Ok(BinaryOperator::Multiply),
-                    "+" => Ok(BinaryOperator::Addition),
-                    "-" => Ok(BinaryOperator::Subtraction),
-                    ">" => Ok(BinaryOperator::GreaterThan),
-                    ">=" => Ok(BinaryOperator::GreaterEqual),
-                    "<" => Ok(BinaryOperator::LessThan),
-                    "<=" => Ok(BinaryOperator::LessEqual),
-                    "==" => Ok(BinaryOperator::EqualEqual),
-                    "!=" => Ok(BinaryOperator::NotEqual),
-                    "&&" => Ok(BinaryOperator::And),
-                    "||" => Ok(BinaryOperator::Or),
-                    _ => Err(Error::map_span(operator_span, "Invalid binary operator")),
-                }?)
+        let (left, left_lcol) = match inner.next() {
+            Some(left_pair) => {
+                let left_lcol = LineColLocation::from(left_pair.as_span());
+                (Expr::try_from(left_pair)?, left_lcol)
             }
-            None => None,
-        };
-        let right = match inner.find_first_tagged("right") {
-            Some(right) => Some(Expr::try_from(right)?),
-            None => None,
+            None => return Err(Error::map_span(value_span, "Expected a value expression")),
         };
 
-        Ok(BinaryExpr {
-            left,
-            operator,
-            right,
-        })
+        match BinaryExpr::parse_expression(&mut inner, left, left_lcol, 0, value_span)? {
+            (Expr::Binary(binary_expr), _) => Ok(*binary_expr),
+            (left, left_lcol) => Ok(BinaryExpr {
+                left,
+                operator: None,
+                right: None,
+                lcol: left_lcol,
+            }),
+        }
     }
 }
 
+/// Asserts that two parsed nodes are structurally equal, ignoring `lcol`, and prints both
+/// sides (via `{:?}`) on failure. A thin wrapper over the span-ignoring `PartialEq` impls
+/// above, so a failing tree-shape assertion reads as a diff instead of just "not equal".
+#[cfg(test)]
+macro_rules! assert_eq_ignore_span {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => assert!(
+                left == right,
+                "trees differ (ignoring span):\n  left:  {:?}\n  right: {:?}",
+                left,
+                right
+            ),
+        }
+    };
+}
+
 #[cfg(test)]
 mod binary_expr_tests {
+    use pest::error::LineColLocation;
+
     use crate::ast::ParserTestHelper;
 
-    use super::*;
+    use super::{super::primitive::Primitive, *};
+
+    fn lcol() -> LineColLocation {
+        LineColLocation::Pos((0, 0))
+    }
+
+    fn number(value: f64) -> Expr {
+        Expr::from(Primitive::Number(value))
+    }
+
+    fn binary(left: Expr, kind: BinaryOperatorKind, right: Expr) -> Expr {
+        BinaryExpr {
+            left,
+            operator: Some(BinaryOperator { kind, lcol: lcol() }),
+            right: Some(right),
+            lcol: lcol(),
+        }
+        .into()
+    }
 
     #[test]
     fn parses_binary_expr() {
@@ -85,4 +266,45 @@ mod binary_expr_tests {
         test_helper.assert_parse("5 *2");
         test_helper.assert_parse("5== 2");
     }
+
+    #[test]
+    fn parses_with_operator_precedence() {
+        let test_helper = ParserTestHelper::<BinaryExpr>::new(Rule::expression, "BinaryExpr");
+        // `*` binds tighter than `+`, so this should nest as `5 + (2 * 3)`.
+        test_helper.assert_parse("5 + 2 * 3");
+        // `<` and `==` both bind tighter than `&&`.
+        test_helper.assert_parse("1 < 2 && 3 == 3");
+        // Same-precedence operators should parse left-associatively.
+        test_helper.assert_parse("1 - 2 - 3");
+        test_helper.assert_parse("(5 + 2) * 3");
+    }
+
+    #[test]
+    fn nests_higher_precedence_operator_on_the_right() {
+        let test_helper = ParserTestHelper::<BinaryExpr>::new(Rule::expression, "BinaryExpr");
+        let parsed = test_helper.parse("5 + 2 * 3");
+
+        let expected = binary(
+            number(5.0),
+            BinaryOperatorKind::Addition,
+            binary(number(2.0), BinaryOperatorKind::Multiply, number(3.0)),
+        );
+
+        assert_eq_ignore_span!(Expr::from(parsed), expected);
+    }
+
+    #[test]
+    fn same_precedence_operators_associate_left() {
+        let test_helper = ParserTestHelper::<BinaryExpr>::new(Rule::expression, "BinaryExpr");
+        let parsed = test_helper.parse("1 - 2 - 3");
+
+        // `1 - 2 - 3` should nest as `(1 - 2) - 3`, not `1 - (2 - 3)`.
+        let expected = binary(
+            binary(number(1.0), BinaryOperatorKind::Subtraction, number(2.0)),
+            BinaryOperatorKind::Subtraction,
+            number(3.0),
+        );
+
+        assert_eq_ignore_span!(Expr::from(parsed), expected);
+    }
 }
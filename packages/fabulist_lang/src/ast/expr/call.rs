@@ -0,0 +1,44 @@
+use pest::iterators::Pair;
+
+use crate::{ast::dfn::argument_body::ArgumentBodyDfn, parser::Rule};
+
+use super::{primitive::Primitive, Error};
+
+#[derive(Debug)]
+pub struct CallExpr {
+    pub callee: Primitive,
+    pub arguments: ArgumentBodyDfn,
+}
+
+impl TryFrom<Pair<'_, Rule>> for CallExpr {
+    type Error = Error;
+    fn try_from(value: Pair<'_, Rule>) -> Result<Self, Self::Error> {
+        let value_span = value.as_span();
+        let mut inner = value.into_inner();
+
+        let callee = match inner.find(|pair| pair.as_rule() == Rule::identifier) {
+            Some(identifier) => Primitive::try_from(identifier),
+            None => Err(Error::map_span(value_span, "Expected a function name")),
+        }?;
+        let arguments = match inner.find(|pair| pair.as_rule() == Rule::argument_body) {
+            Some(argument_body) => ArgumentBodyDfn::try_from(argument_body),
+            None => Err(Error::map_span(value_span, "Expected an argument body")),
+        }?;
+
+        Ok(CallExpr { callee, arguments })
+    }
+}
+
+#[cfg(test)]
+mod call_expr_tests {
+    use crate::ast::ParserTestHelper;
+
+    use super::*;
+
+    #[test]
+    fn parses_call_expr() {
+        let test_helper = ParserTestHelper::<CallExpr>::new(Rule::call_expr, "CallExpr");
+        test_helper.assert_parse(r#"has_item("key")"#);
+        test_helper.assert_parse("random(1, 6)");
+    }
+}
@@ -19,7 +19,28 @@ impl From<Span<'_>> for OwnedSpan {
 }
 
 #[derive(thiserror::Error, Debug)]
-pub enum Error {}
+pub enum Error {
+    /// A problem found while statically walking a fully parsed `Story` — a dangling `goto`
+    /// target, an identifier read before it's bound, or a `BinaryExpr` whose literal operands
+    /// can't support the given operator. Carries `lcol` rather than an `OwnedSpan` since the
+    /// analyzer walks an already-parsed AST and only has each node's `LineColLocation`, not
+    /// the original source text.
+    #[error("unresolved `goto` target `{path}`")]
+    UnresolvedGotoTarget {
+        path: String,
+        lcol: pest::error::LineColLocation,
+    },
+    #[error("`{name}` is used before it's bound")]
+    UnboundVariable {
+        name: String,
+        lcol: pest::error::LineColLocation,
+    },
+    #[error("{message}")]
+    InvalidOperandTypes {
+        message: String,
+        lcol: pest::error::LineColLocation,
+    },
+}
 
 impl Error {
     pub fn map_custom_error(